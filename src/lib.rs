@@ -8,18 +8,22 @@ use alkanes_runtime::{
 };
 
 use alkanes_support::{
+  cellpack::Cellpack,
   id::AlkaneId,
-  parcel::AlkaneTransfer, response::CallResponse,
+  parcel::{AlkaneTransfer, AlkaneTransferParcel}, response::CallResponse,
   utils::overflow_error
 };
 
-use bitcoin::hashes::Hash;
-use bitcoin::{Txid, Transaction};
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::{Block, Txid, Transaction};
 
 use anyhow::{anyhow, Result};
 use std::sync::Arc;
 
-// We could validate pandas ids against the collection contract 2:614, but we cbf. Save fuel.
+// Cheap default: trust a baked-in table instead of calling out to the collection
+// contract. A future commit can switch to the authoritative on-chain check by
+// flipping STRICT_VALIDATION_AVAILABLE once it's confirmed safe; see
+// is_valid_panda / is_valid_panda_strict.
 mod panda_ids;
 use panda_ids::PANDA_IDS;
 
@@ -27,11 +31,30 @@ mod panda_image;
 use panda_image::PANDA_IMAGE;
 
 const PANDA_BLOCK: u128 = 0x2;
+const PANDA_COLLECTION_TX: u128 = 614;
+
+// Opcode the 2:614 collection contract is believed to expose for membership
+// checks: given a candidate child tx, it should return a single 0/1 byte.
+// UNVERIFIED against the deployed contract's actual ABI — do not flip
+// STRICT_VALIDATION_AVAILABLE on until this has been confirmed on-chain.
+const COLLECTION_OPCODE_IS_MEMBER: u128 = 1000;
+
+// Kept off until COLLECTION_OPCODE_IS_MEMBER is confirmed against the real
+// 2:614 contract: shipping a wrong opcode number here would make strict mode
+// either reject every legitimate Panda or silently wave forged ones through,
+// which is exactly the hole this mode is meant to close.
+const STRICT_VALIDATION_AVAILABLE: bool = false;
 
 const BAMBOO_PER_PANDA: u128 = 10_000_000_000_000;
 const PANDA_SUPPLY: u128 = 10_000;
 const BAMBOO_CAP: u128 = PANDA_SUPPLY * BAMBOO_PER_PANDA;
 
+// direction, txid, panda count, bamboo delta, block height
+const EVENT_RECORD_LEN: usize = 1 + 32 + 16 + 16 + 8;
+
+const EVENT_DIRECTION_PANDA_TO_BAMBOO: u8 = 0;
+const EVENT_DIRECTION_BAMBOO_TO_PANDA: u8 = 1;
+
 #[derive(Default)]
 pub struct BambooSwap(());
 
@@ -48,9 +71,18 @@ enum BambooSwapMessage {
   #[opcode(69)]
   BambooToPanda,
 
+  #[opcode(70)]
+  BambooToPandaRandom,
+
+  #[opcode(71)]
+  BambooToPandaById,
+
   #[opcode(77)]
   MintTokens,
 
+  #[opcode(78)]
+  WithdrawFees,
+
   #[opcode(99)]
   #[returns(String)]
   GetName,
@@ -75,6 +107,14 @@ enum BambooSwapMessage {
   #[returns(u128)]
   GetValuePerMint,
 
+  #[opcode(106)]
+  #[returns(u128)]
+  GetFeeBps,
+
+  #[opcode(107)]
+  #[returns(u128)]
+  GetFeeReserve,
+
   #[opcode(1000)]
   #[returns(Vec<u8>)]
   GetData,
@@ -90,6 +130,18 @@ enum BambooSwapMessage {
   #[opcode(2002)]
   #[returns(String)]
   GetPandaStackJson,
+
+  #[opcode(3000)]
+  #[returns(u128)]
+  GetEventsCount,
+
+  #[opcode(3001)]
+  #[returns(Vec<u8>)]
+  GetEvents,
+
+  #[opcode(3002)]
+  #[returns(String)]
+  GetEventsJson,
 }
 
 impl Token for BambooSwap {
@@ -107,10 +159,74 @@ impl BambooSwap {
     self.observe_initialization()?;
     let context = self.context()?;
 
+    // Panda ID validation mode is a compile-time choice (STRICT_VALIDATION_AVAILABLE),
+    // not a per-deployment one: see is_valid_panda. There is deliberately no
+    // constructor input or opcode for it until COLLECTION_OPCODE_IS_MEMBER is
+    // confirmed against the real 2:614 contract — exposing one now would let a
+    // deployer "choose" a mode that can never actually take effect.
+    let fee_bps = context.inputs.get(0).copied().unwrap_or(0);
+    if fee_bps > 10_000 {
+      return Err(anyhow!("fee_bps cannot exceed 10000 (100%)"));
+    }
+    self.set_fee_bps(fee_bps);
+
+    let fee_recipient = AlkaneId {
+      block: context.inputs.get(1).copied().unwrap_or(0),
+      tx: context.inputs.get(2).copied().unwrap_or(0),
+    };
+
+    // A zero AlkaneId can never be a valid fee recipient: it must not be
+    // possible to accrue fees nobody but a forged/default caller can claim.
+    if fee_bps > 0 && fee_recipient == (AlkaneId { block: 0, tx: 0 }) {
+      return Err(anyhow!("fee_recipient must be set when fee_bps is nonzero"));
+    }
+    self.set_fee_recipient(&fee_recipient);
+
     let response = CallResponse::forward(&context.incoming_alkanes);
     Ok(response)
   }
 
+  fn get_fee_bps(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.fee_bps().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_fee_reserve(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.fee_reserve().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn withdraw_fees(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+
+    if context.caller != self.fee_recipient() {
+      return Err(anyhow!("Only the configured fee recipient may withdraw fees"));
+    }
+
+    let amount = self.fee_reserve();
+    if amount == 0 {
+      return Err(anyhow!("No fees to withdraw"));
+    }
+
+    self.set_fee_reserve(0);
+
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+    response.alkanes.0.push(AlkaneTransfer {
+      id: context.myself.clone(),
+      value: amount,
+    });
+
+    Ok(response)
+  }
+
   fn get_name(&self) -> Result<CallResponse> {
     let context = self.context()?;
     let mut response = CallResponse::forward(&context.incoming_alkanes);
@@ -196,8 +312,131 @@ impl BambooSwap {
     Ok(())
   }
 
-  fn is_valid_panda(&self, id: &AlkaneId) -> Result<bool> {
-    Ok(id.block == PANDA_BLOCK && PANDA_IDS.contains(&id.tx))
+  fn fee_bps_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/fee_bps")
+  }
+
+  fn fee_bps(&self) -> u128 {
+    self.fee_bps_pointer().get_value::<u128>()
+  }
+
+  fn set_fee_bps(&self, bps: u128) {
+    self.fee_bps_pointer().set_value::<u128>(bps);
+  }
+
+  fn fee_reserve_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/fee_reserve")
+  }
+
+  fn fee_reserve(&self) -> u128 {
+    self.fee_reserve_pointer().get_value::<u128>()
+  }
+
+  fn set_fee_reserve(&self, v: u128) {
+    self.fee_reserve_pointer().set_value::<u128>(v);
+  }
+
+  fn increase_fee_reserve(&self, v: u128) -> Result<()> {
+    self.set_fee_reserve(overflow_error(self.fee_reserve().checked_add(v))?);
+    Ok(())
+  }
+
+  fn fee_recipient_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/fee_recipient")
+  }
+
+  fn fee_recipient(&self) -> AlkaneId {
+    let bytes = self.fee_recipient_pointer().get();
+    if bytes.len() != 32 {
+      return AlkaneId { block: 0, tx: 0 };
+    }
+
+    AlkaneId {
+      block: u128::from_le_bytes(bytes[..16].try_into().unwrap()),
+      tx: u128::from_le_bytes(bytes[16..].try_into().unwrap()),
+    }
+  }
+
+  fn set_fee_recipient(&self, id: &AlkaneId) {
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&id.block.to_le_bytes());
+    bytes.extend_from_slice(&id.tx.to_le_bytes());
+
+    self.fee_recipient_pointer().set(Arc::new(bytes));
+  }
+
+  // Prices a redemption of `panda_count` Pandas as (required, base, fee):
+  // base is `panda_count * BAMBOO_PER_PANDA` (burned against total_supply),
+  // fee is the /fee_bps spread on top of it (retained in /fee_reserve), and
+  // required is their sum, the amount the caller must supply.
+  fn bamboo_swap_breakdown(&self, panda_count: u128) -> Result<(u128, u128, u128)> {
+    let base = panda_count.checked_mul(BAMBOO_PER_PANDA)
+      .ok_or_else(|| anyhow!("Bamboo amount overflow"))?;
+
+    let fee_bps = self.fee_bps();
+    let required = base.checked_mul(10_000 + fee_bps)
+      .ok_or_else(|| anyhow!("Bamboo amount overflow"))?
+      / 10_000;
+
+    let fee = required - base;
+
+    Ok((required, base, fee))
+  }
+
+  fn bamboo_unit_with_fee(&self) -> Result<u128> {
+    Ok(self.bamboo_swap_breakdown(1)?.0)
+  }
+
+  // Mirrors bamboo_swap_breakdown for the other direction: mints slightly
+  // less than BAMBOO_PER_PANDA per deposited Panda by the same /fee_bps ratio.
+  fn bamboo_mint_amount(&self, panda_count: u128) -> Result<u128> {
+    let base = panda_count.checked_mul(BAMBOO_PER_PANDA)
+      .ok_or_else(|| anyhow!("Bamboo amount overflow"))?;
+
+    let fee_bps = self.fee_bps();
+    if fee_bps == 0 {
+      return Ok(base);
+    }
+
+    Ok(base.checked_mul(10_000)
+      .ok_or_else(|| anyhow!("Bamboo amount overflow"))?
+      / (10_000 + fee_bps))
+  }
+
+  // Validation mode is a compile-time choice, not a deployer-facing one: until
+  // COLLECTION_OPCODE_IS_MEMBER is confirmed against the real 2:614 contract,
+  // STRICT_VALIDATION_AVAILABLE stays false and this always takes the cheap
+  // path. Flipping that const on is the entire follow-up — no opcode, state,
+  // or migration needed on top of it.
+  fn is_valid_panda(&self, id: &AlkaneId, fuel: u64) -> Result<bool> {
+    if STRICT_VALIDATION_AVAILABLE {
+      self.is_valid_panda_strict(id, fuel)
+    } else {
+      Ok(id.block == PANDA_BLOCK && PANDA_IDS.contains(&id.tx))
+    }
+  }
+
+  // Authoritative check: ask the collection contract itself whether `id` is
+  // one of its children, instead of trusting the baked-in PANDA_IDS table.
+  // `fuel` is the caller's bounded share of the remaining budget, not the
+  // entire remaining budget — see panda_to_bamboo.
+  fn is_valid_panda_strict(&self, id: &AlkaneId, fuel: u64) -> Result<bool> {
+    if id.block != PANDA_BLOCK {
+      return Ok(false);
+    }
+
+    let cellpack = Cellpack {
+      target: AlkaneId { block: PANDA_BLOCK, tx: PANDA_COLLECTION_TX },
+      inputs: vec![COLLECTION_OPCODE_IS_MEMBER, id.tx],
+    };
+
+    let response = self.staticcall(
+      &cellpack,
+      &AlkaneTransferParcel::default(),
+      fuel,
+    )?;
+
+    Ok(response.data.first().copied() == Some(1u8))
   }
 
   fn panda_to_bamboo(&self) -> Result<CallResponse> {
@@ -216,25 +455,31 @@ impl BambooSwap {
     self.add_tx_hash(&txid)?;
 
     let mut response = CallResponse::default();
-    let mut total_bamboo = 0u128;
+
+    // Bound each is_valid_panda_strict call to an even share of the remaining
+    // fuel instead of handing over the entire budget per incoming alkane,
+    // which would starve later iterations (or the rest of the transaction).
+    let validation_fuel = self.fuel() / (context.incoming_alkanes.0.len() as u64).max(1);
 
     for alkane in context.incoming_alkanes.0.iter() {
-      if !self.is_valid_panda(&alkane.id)? {
+      if !self.is_valid_panda(&alkane.id, validation_fuel)? {
         return Err(anyhow!("Invalid Panda ID"));
       }
 
       self.add_instance(&alkane.id)?;
-
-      total_bamboo = total_bamboo.checked_add(BAMBOO_PER_PANDA)
-        .ok_or_else(|| anyhow!("Bamboo amount overflow"))?;
     }
 
+    let panda_count = context.incoming_alkanes.0.len() as u128;
+    let total_bamboo = self.bamboo_mint_amount(panda_count)?;
+
     self.increase_total_supply(total_bamboo)?;
 
     response.alkanes.0.push(AlkaneTransfer {
       id: context.myself.clone(),
       value: total_bamboo,
-    }); 
+    });
+
+    self.append_event(EVENT_DIRECTION_PANDA_TO_BAMBOO, &txid, panda_count, total_bamboo)?;
 
     Ok(response)
   }
@@ -257,15 +502,16 @@ impl BambooSwap {
       return Err(anyhow!("Supplied alkane is not $BAMBOO"));
     }
 
-    if transfer.value < BAMBOO_PER_PANDA {
+    let unit = self.bamboo_unit_with_fee()?;
+    if transfer.value < unit {
       return Err(anyhow!(
         "Not enough $BAMBOO supplied to swap"
       ));
     }
 
-    let panda_count = transfer.value / BAMBOO_PER_PANDA;
-    let bamboo_used = panda_count * BAMBOO_PER_PANDA;
-    let bamboo_change = transfer.value % BAMBOO_PER_PANDA;
+    let panda_count = transfer.value / unit;
+    let (bamboo_used, bamboo_base, fee) = self.bamboo_swap_breakdown(panda_count)?;
+    let bamboo_change = transfer.value - bamboo_used;
 
     let count = self.instances_count();
     if count < panda_count {
@@ -276,8 +522,9 @@ impl BambooSwap {
 
     let mut response = CallResponse::default();
 
-    self.decrease_total_supply(bamboo_used)?;
-  
+    self.decrease_total_supply(bamboo_base)?;
+    self.increase_fee_reserve(fee)?;
+
     // Pandas
     for _ in 0..panda_count {
       response.alkanes.0.push(AlkaneTransfer {
@@ -294,9 +541,190 @@ impl BambooSwap {
       });
     }
 
+    self.append_event(EVENT_DIRECTION_BAMBOO_TO_PANDA, &txid, panda_count, bamboo_base)?;
+
     Ok(response)
   }
 
+  // Draws are seeded from the confirming block's hash together with the swap
+  // txid (see swap_seed), so the outcome is unknown until the block is mined
+  // but anyone can recompute it afterward from on-chain data alone.
+  fn bamboo_to_panda_random(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let txid = self.transaction_id()?;
+
+    // Enforce one swap per transaction
+    if self.has_tx_hash(&txid) {
+      return Err(anyhow!("Transaction already used for swap"));
+    }
+
+    if context.incoming_alkanes.0.len() != 1 {
+      return Err(anyhow!("Must send $BAMBOO to swap"));
+    }
+
+    let transfer = context.incoming_alkanes.0[0].clone();
+    if transfer.id != context.myself.clone() {
+      return Err(anyhow!("Supplied alkane is not $BAMBOO"));
+    }
+
+    let unit = self.bamboo_unit_with_fee()?;
+    if transfer.value < unit {
+      return Err(anyhow!(
+        "Not enough $BAMBOO supplied to swap"
+      ));
+    }
+
+    let panda_count = transfer.value / unit;
+    let (bamboo_used, bamboo_base, fee) = self.bamboo_swap_breakdown(panda_count)?;
+    let bamboo_change = transfer.value - bamboo_used;
+
+    let mut remaining = self.instances_count();
+    if remaining < panda_count {
+      return Err(anyhow!("Not enough Pandas available to swap"));
+    }
+
+    let mut state = self.swap_seed(&txid)?;
+
+    self.add_tx_hash(&txid)?;
+
+    let mut response = CallResponse::default();
+
+    self.decrease_total_supply(bamboo_base)?;
+    self.increase_fee_reserve(fee)?;
+
+    // Pandas
+    for _ in 0..panda_count {
+      let idx = state % remaining;
+
+      response.alkanes.0.push(AlkaneTransfer {
+        id: self.swap_remove_instance(idx)?,
+        value: 1u128,
+      });
+
+      remaining -= 1;
+      state = Self::advance_seed(state);
+    }
+
+    // Change
+    if bamboo_change > 0 {
+      response.alkanes.0.push(AlkaneTransfer {
+        id: context.myself.clone(),
+        value: bamboo_change,
+      });
+    }
+
+    self.append_event(EVENT_DIRECTION_BAMBOO_TO_PANDA, &txid, panda_count, bamboo_base)?;
+
+    Ok(response)
+  }
+
+  // Seeds the draw from the hash of the block the swap confirms in, not just
+  // the txid: compute_txid() only covers inputs/outputs/sequence numbers, so
+  // a redeemer can grind unsigned-tx variants offline until one produces the
+  // Panda(s) they want without the block hash ever being at risk. The block
+  // hash isn't known to anyone until the block is mined, so it can't be
+  // chosen ex ante by the party building the redemption transaction.
+  fn swap_seed(&self, txid: &Txid) -> Result<u128> {
+    let block = consensus_decode::<Block>(&mut std::io::Cursor::new(self.block()))?;
+
+    let mut preimage = block.block_hash().as_byte_array().to_vec();
+    preimage.extend_from_slice(txid.as_byte_array());
+    preimage.extend_from_slice(&self.instances_count().to_le_bytes());
+
+    let digest = sha256::Hash::hash(&preimage).to_byte_array();
+    Ok(u128::from_le_bytes(digest[..16].try_into().unwrap()))
+  }
+
+  fn advance_seed(state: u128) -> u128 {
+    state
+      .wrapping_mul(6364136223846793005)
+      .wrapping_add(1442695040888963407)
+  }
+
+  // Redeems specific Pandas by id instead of whatever the stack or PRNG would
+  // hand back. `context.inputs` is read as flat (block, tx) pairs.
+  fn bamboo_to_panda_by_id(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let txid = self.transaction_id()?;
+
+    // Enforce one swap per transaction
+    if self.has_tx_hash(&txid) {
+      return Err(anyhow!("Transaction already used for swap"));
+    }
+
+    if context.incoming_alkanes.0.len() != 1 {
+      return Err(anyhow!("Must send $BAMBOO to swap"));
+    }
+
+    let transfer = context.incoming_alkanes.0[0].clone();
+    if transfer.id != context.myself.clone() {
+      return Err(anyhow!("Supplied alkane is not $BAMBOO"));
+    }
+
+    if context.inputs.is_empty() || context.inputs.len() % 2 != 0 {
+      return Err(anyhow!("Must supply a list of block:tx pairs to redeem"));
+    }
+
+    let requested: Vec<AlkaneId> = context.inputs
+      .chunks(2)
+      .map(|pair| AlkaneId { block: pair[0], tx: pair[1] })
+      .collect();
+
+    let panda_count = requested.len() as u128;
+    let (bamboo_used, bamboo_base, fee) = self.bamboo_swap_breakdown(panda_count)?;
+
+    if transfer.value < bamboo_used {
+      return Err(anyhow!("Not enough $BAMBOO supplied to swap"));
+    }
+
+    let bamboo_change = transfer.value - bamboo_used;
+
+    self.add_tx_hash(&txid)?;
+
+    let mut response = CallResponse::default();
+
+    self.decrease_total_supply(bamboo_base)?;
+    self.increase_fee_reserve(fee)?;
+
+    // Look up and remove one at a time: swap-remove relocates the tail
+    // element on every call, so an index computed ahead of time could point
+    // at the wrong Panda by the time it's used.
+    for id in requested.iter() {
+      let index = self.find_instance_index(id)?
+        .ok_or_else(|| anyhow!("Requested Panda {}:{} is not in the vault", id.block, id.tx))?;
+
+      response.alkanes.0.push(AlkaneTransfer {
+        id: self.swap_remove_instance(index)?,
+        value: 1u128,
+      });
+    }
+
+    // Change
+    if bamboo_change > 0 {
+      response.alkanes.0.push(AlkaneTransfer {
+        id: context.myself.clone(),
+        value: bamboo_change,
+      });
+    }
+
+    self.append_event(EVENT_DIRECTION_BAMBOO_TO_PANDA, &txid, panda_count, bamboo_base)?;
+
+    Ok(response)
+  }
+
+  fn find_instance_index(&self, id: &AlkaneId) -> Result<Option<u128>> {
+    let count = self.instances_count();
+
+    for i in 0..count {
+      let candidate = self.lookup_instance(i)?;
+      if candidate.block == id.block && candidate.tx == id.tx {
+        return Ok(Some(i));
+      }
+    }
+
+    Ok(None)
+  }
+
   fn mint_tokens(&self) -> Result<CallResponse> {
     return Err(anyhow!("Minting not implemented"));
   }
@@ -350,6 +778,140 @@ impl BambooSwap {
     Ok(response)
   }
 
+  fn get_events_count(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.events_count().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_events(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let (start, end) = self.events_page_bounds(&context.inputs)?;
+    let mut flattened = Vec::new();
+
+    for i in start..end {
+      flattened.extend(self.lookup_event(i)?);
+    }
+
+    response.data = flattened;
+    Ok(response)
+  }
+
+  fn get_events_json(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let (start, end) = self.events_page_bounds(&context.inputs)?;
+    let mut events = Vec::new();
+
+    for i in start..end {
+      events.push(Self::render_event_json(&self.lookup_event(i)?)?);
+    }
+
+    response.data = serde_json::to_string(&events)?.into_bytes();
+    Ok(response)
+  }
+
+  // Reads `[start, limit]` from the opcode inputs and clamps the page to the
+  // current events_count, mirroring how get_panda_stack_json walks /instances.
+  fn events_page_bounds(&self, inputs: &[u128]) -> Result<(u128, u128)> {
+    let start = inputs.get(0).copied().unwrap_or(0);
+    let limit = inputs.get(1).copied().unwrap_or(0);
+
+    let count = self.events_count();
+    let end = start.saturating_add(limit).min(count);
+    let start = start.min(end);
+
+    Ok((start, end))
+  }
+
+  fn render_event_json(bytes: &[u8]) -> Result<serde_json::Value> {
+    if bytes.len() != EVENT_RECORD_LEN {
+      return Err(anyhow!("Invalid event record length"));
+    }
+
+    let direction = bytes[0];
+    let txid = Txid::from_slice(&bytes[1..33])?;
+    let panda_count = u128::from_le_bytes(bytes[33..49].try_into().unwrap());
+    let bamboo_delta = u128::from_le_bytes(bytes[49..65].try_into().unwrap());
+    let height = u64::from_le_bytes(bytes[65..73].try_into().unwrap());
+
+    Ok(serde_json::json!({
+      "direction": if direction == EVENT_DIRECTION_PANDA_TO_BAMBOO {
+        "panda_to_bamboo"
+      } else {
+        "bamboo_to_panda"
+      },
+      "txid": txid.to_string(),
+      "panda_count": panda_count.to_string(),
+      "bamboo_delta": bamboo_delta.to_string(),
+      "height": height,
+    }))
+  }
+
+  fn events_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/events")
+  }
+
+  fn events_count(&self) -> u128 {
+    self.events_pointer().get_value::<u128>()
+  }
+
+  fn set_events_count(&self, count: u128) {
+    self.events_pointer().set_value::<u128>(count);
+  }
+
+  fn append_event(
+    &self,
+    direction: u8,
+    txid: &Txid,
+    panda_count: u128,
+    bamboo_delta: u128,
+  ) -> Result<()> {
+    let count = self.events_count();
+    let new_count = count.checked_add(1)
+      .ok_or_else(|| anyhow!("events count overflow"))?;
+
+    // Pin the height field to u64 explicitly: EVENT_RECORD_LEN assumes 8
+    // bytes here, and a silent width mismatch would brick every page read
+    // behind lookup_event's strict length check.
+    let height: u64 = self.height() as u64;
+
+    let mut bytes = Vec::with_capacity(EVENT_RECORD_LEN);
+    bytes.push(direction);
+    bytes.extend_from_slice(txid.as_byte_array());
+    bytes.extend_from_slice(&panda_count.to_le_bytes());
+    bytes.extend_from_slice(&bamboo_delta.to_le_bytes());
+    bytes.extend_from_slice(&height.to_le_bytes());
+
+    debug_assert_eq!(bytes.len(), EVENT_RECORD_LEN, "event record width drifted from EVENT_RECORD_LEN");
+
+    let bytes_vec = new_count.to_le_bytes().to_vec();
+    let mut event_pointer = self.events_pointer().select(&bytes_vec);
+    event_pointer.set(Arc::new(bytes));
+
+    self.set_events_count(new_count);
+
+    Ok(())
+  }
+
+  fn lookup_event(&self, index: u128) -> Result<Vec<u8>> {
+    let bytes_vec = (index + 1).to_le_bytes().to_vec();
+    let event_pointer = self.events_pointer().select(&bytes_vec);
+
+    let bytes = event_pointer.get();
+    if bytes.len() != EVENT_RECORD_LEN {
+      return Err(anyhow!("Invalid event record length"));
+    }
+
+    Ok(bytes.as_ref().clone())
+  }
+
   fn instances_pointer(&self) -> StoragePointer {
     StoragePointer::from_keyword("/instances")
   }
@@ -398,6 +960,41 @@ impl BambooSwap {
     Ok(instance_id)
   }
 
+  // Swap-remove: moves the tail element into the removed slot and truncates,
+  // keeping /instances dense without shifting every element behind it.
+  fn swap_remove_instance(&self, index: u128) -> Result<AlkaneId> {
+    let count = self.instances_count();
+
+    let new_count = count.checked_sub(1)
+      .ok_or_else(|| anyhow!("instances count underflow"))?;
+
+    if index >= count {
+      return Err(anyhow!("instance index out of bounds"));
+    }
+
+    let removed = self.lookup_instance(index)?;
+
+    if index != new_count {
+      let last = self.lookup_instance(new_count)?;
+
+      let mut bytes = Vec::with_capacity(32);
+      bytes.extend_from_slice(&last.block.to_le_bytes());
+      bytes.extend_from_slice(&last.tx.to_le_bytes());
+
+      let bytes_vec = (index + 1).to_le_bytes().to_vec();
+      let mut instance_pointer = self.instances_pointer().select(&bytes_vec);
+      instance_pointer.set(Arc::new(bytes));
+    }
+
+    let last_bytes_vec = (new_count + 1).to_le_bytes().to_vec();
+    let mut last_pointer = self.instances_pointer().select(&last_bytes_vec);
+    last_pointer.set(Arc::new(Vec::new()));
+
+    self.set_instances_count(new_count);
+
+    Ok(removed)
+  }
+
   fn lookup_instance(&self, index: u128) -> Result<AlkaneId> {
     let bytes_vec = (index + 1).to_le_bytes().to_vec();
     let instance_pointer = self.instances_pointer().select(&bytes_vec);